@@ -94,93 +94,146 @@
 //!
 //! It is the users' responsibility to avoid these types of scenarios.
 
-use std::mem::size_of;
-use std::slice;
+#![no_std]
 
-/// Reinterpret a given slice as a slice of another type. This function checks that the resulting
-/// slice is appropriately sized.
-pub unsafe fn reinterpret_mut_slice<T, S>(slice: &mut [T]) -> &mut [S] {
-    let size_t = size_of::<T>();
-    let size_s = size_of::<S>();
-    let nu_len = if size_t > 0 {
-        assert_ne!(
-            size_s, 0,
-            "Cannot reinterpret a slice of non-zero sized types as a slice of zero sized types."
-        );
-        // We must be able to split the given slice into appropriately sized chunks.
-        assert_eq!(
-            (slice.len() * size_t) % size_s,
-            0,
-            "Slice cannot be safely reinterpreted due to a misaligned size"
-        );
-        (slice.len() * size_t) / size_s
-    } else {
-        assert_eq!(
-            size_s, 0,
-            "Cannot reinterpret a slice of zero sized types as a slice of non-zero sized types."
-        );
-        slice.len()
-    };
-    slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut S, nu_len)
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::mem::{align_of, size_of};
+use core::ptr::NonNull;
+use core::slice;
+
+/// The reason a reinterpretation could not be performed.
+///
+/// Returned by the `try_reinterpret_*` functions to report, without panicking, why a conversion
+/// between two layouts is unsound.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReinterpretError {
+    /// The total number of bytes in the source does not divide evenly into the target element
+    /// size.
+    MisalignedLength,
+    /// The source `Vec`'s capacity (in bytes) does not divide evenly into the target element size,
+    /// so the reinterpreted `Vec` cannot describe the same allocation.
+    MisalignedCapacity,
+    /// The source pointer does not satisfy the alignment required by the target type.
+    MisalignedPointer,
+    /// A zero sized type was reinterpreted as a non-zero sized type or vice versa.
+    ZeroSizedMismatch,
 }
 
-/// Reinterpret a given slice as a slice of another type. This function checks that the resulting
-/// slice is appropriately sized.
-pub unsafe fn reinterpret_slice<T, S>(slice: &[T]) -> &[S] {
-    let size_t = size_of::<T>();
-    let size_s = size_of::<S>();
-    let nu_len = if size_t > 0 {
-        assert_ne!(
-            size_s, 0,
-            "Cannot reinterpret a slice of non-zero sized types as a slice of zero sized types."
-        );
-        // We must be able to split the given slice into appropriately sized chunks.
-        assert_eq!(
-            (slice.len() * size_t) % size_s,
+impl fmt::Display for ReinterpretError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self {
+            ReinterpretError::MisalignedLength => {
+                "data cannot be safely reinterpreted due to a misaligned size"
+            }
+            ReinterpretError::MisalignedCapacity => {
+                "Vec cannot be safely reinterpreted due to a misaligned capacity"
+            }
+            ReinterpretError::MisalignedPointer => {
+                "data cannot be safely reinterpreted due to a misaligned pointer"
+            }
+            ReinterpretError::ZeroSizedMismatch => {
+                "cannot reinterpret between zero sized and non-zero sized types"
+            }
+        };
+        f.write_str(msg)
+    }
+}
+
+impl core::error::Error for ReinterpretError {}
+
+/// Reinterpret a given slice as a slice of another type, returning an error instead of panicking
+/// when the conversion is unsound. This function checks that the resulting slice is appropriately
+/// sized and aligned.
+///
+/// # Safety
+///
+/// The caller must ensure that every bit pattern in the source is a valid value of the target type
+/// `S`; this function checks the layout but not the validity of the reinterpreted contents.
+pub unsafe fn try_reinterpret_mut_slice<T, S>(
+    slice: &mut [T],
+) -> Result<&mut [S], ReinterpretError> {
+    let nu_len = reinterpreted_len::<T, S>(slice.len())?;
+    // An empty result never dereferences the pointer, but `from_raw_parts_mut` still requires an
+    // aligned, non-null pointer, so hand it a dangling one rather than the (possibly misaligned)
+    // source pointer.
+    if nu_len == 0 {
+        return Ok(slice::from_raw_parts_mut(NonNull::<S>::dangling().as_ptr(), 0));
+    }
+    check_alignment::<S>(slice.as_mut_ptr() as usize)?;
+    Ok(slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut S, nu_len))
+}
+
+/// Reinterpret a given slice as a slice of another type, returning an error instead of panicking
+/// when the conversion is unsound. This function checks that the resulting slice is appropriately
+/// sized and aligned.
+///
+/// # Safety
+///
+/// The caller must ensure that every bit pattern in the source is a valid value of the target type
+/// `S`; this function checks the layout but not the validity of the reinterpreted contents.
+pub unsafe fn try_reinterpret_slice<T, S>(slice: &[T]) -> Result<&[S], ReinterpretError> {
+    let nu_len = reinterpreted_len::<T, S>(slice.len())?;
+    // An empty result never dereferences the pointer, but `from_raw_parts` still requires an
+    // aligned, non-null pointer, so hand it a dangling one rather than the (possibly misaligned)
+    // source pointer.
+    if nu_len == 0 {
+        return Ok(slice::from_raw_parts(
+            NonNull::<S>::dangling().as_ptr() as *const S,
             0,
-            "Slice cannot be safely reinterpreted due to a misaligned size"
-        );
-        (slice.len() * size_t) / size_s
-    } else {
-        assert_eq!(
-            size_s, 0,
-            "Cannot reinterpret a slice of zero sized types as a slice of non-zero sized types."
-        );
-        slice.len()
-    };
-    slice::from_raw_parts(slice.as_ptr() as *const S, nu_len)
+        ));
+    }
+    check_alignment::<S>(slice.as_ptr() as usize)?;
+    Ok(slice::from_raw_parts(slice.as_ptr() as *const S, nu_len))
 }
 
-/// Reinterpret a given `Vec` as a `Vec` of another type. This function checks that the resulting
-/// `Vec` is appropriately sized.
-pub unsafe fn reinterpret_vec<T, S>(mut vec: Vec<T>) -> Vec<S> {
+/// Reinterpret a given `Vec` as a `Vec` of another type, returning an error instead of panicking
+/// when the conversion is unsound. This function checks that the resulting `Vec` is appropriately
+/// sized, has a compatible capacity and is correctly aligned.
+///
+/// # Safety
+///
+/// The caller must ensure that every bit pattern in the source is a valid value of the target type
+/// `S`; this function checks the layout but not the validity of the reinterpreted contents.
+/// Additionally, the allocation is reused in place, so reinterpreting to a type with a different
+/// alignment than `T` produces a `Vec` that deallocates with a mismatched `Layout`; use
+/// [`reinterpret_vec_or_copy`] when `align_of::<S>()` may differ from `align_of::<T>()`.
+#[cfg(feature = "alloc")]
+pub unsafe fn try_reinterpret_vec<T, S>(mut vec: Vec<T>) -> Result<Vec<S>, ReinterpretError> {
     let size_t = size_of::<T>();
     let size_s = size_of::<S>();
     let nu_vec = if size_t > 0 {
-        assert_ne!(
-            size_s, 0,
-            "Cannot reinterpret a Vec of non-zero sized types as a Vec of zero sized types."
-        );
-        // We must be able to split the given vec into appropriately sized chunks.
-        assert_eq!(
-            (vec.len() * size_t) % size_s,
-            0,
-            "Vec cannot be safely reinterpreted due to a misaligned size"
-        );
-        let nu_len = (vec.len() * size_t) / size_s;
-        assert_eq!(
-            (vec.capacity() * size_t) % size_s,
-            0,
-            "Vec cannot be safely reinterpreted due to a misaligned capacity"
-        );
+        let nu_len = reinterpreted_len::<T, S>(vec.len())?;
+        // The capacity describes the whole allocation and must split into the target element size
+        // just as the length does.
+        if !(vec.capacity() * size_t).is_multiple_of(size_s) {
+            return Err(ReinterpretError::MisalignedCapacity);
+        }
         let nu_capacity = (vec.capacity() * size_t) / size_s;
+        // An unallocated `Vec` (zero capacity) carries no buffer to reuse and its dangling pointer
+        // is only aligned to `T`, so return a fresh empty `Vec<S>` instead of rebuilding one from a
+        // possibly misaligned pointer.
+        if nu_capacity == 0 {
+            return Ok(Vec::new());
+        }
         let vec_ptr = vec.as_mut_ptr();
+        // Reject a pointer that is not aligned for `S`. Note this only checks the address: it does
+        // *not* guarantee the eventual `dealloc` `Layout` matches the original allocation when
+        // `align_of::<S>() != align_of::<T>()`. Callers who need that guarantee must use
+        // `reinterpret_vec_or_copy`, which falls back to a copy across an alignment change.
+        check_alignment::<S>(vec_ptr as usize)?;
         Vec::from_raw_parts(vec_ptr as *mut S, nu_len, nu_capacity)
     } else {
-        assert_eq!(
-            size_s, 0,
-            "Cannot reinterpret a Vec of zero sized types as a Vec of non-zero sized types."
-        );
+        if size_s != 0 {
+            return Err(ReinterpretError::ZeroSizedMismatch);
+        }
         let nu_len = vec.len();
         let nu_capacity = vec.capacity();
         debug_assert_eq!(
@@ -191,13 +244,349 @@ pub unsafe fn reinterpret_vec<T, S>(mut vec: Vec<T>) -> Vec<S> {
         let vec_ptr = vec.as_mut_ptr();
         Vec::from_raw_parts(vec_ptr as *mut S, nu_len, nu_capacity)
     };
-    ::std::mem::forget(vec);
+    core::mem::forget(vec);
+    Ok(nu_vec)
+}
+
+/// Compute the length of a slice or `Vec` reinterpreted from `T` to `S`, reporting the relevant
+/// error when the two layouts are incompatible.
+fn reinterpreted_len<T, S>(len: usize) -> Result<usize, ReinterpretError> {
+    let size_t = size_of::<T>();
+    let size_s = size_of::<S>();
+    if size_t > 0 {
+        if size_s == 0 {
+            return Err(ReinterpretError::ZeroSizedMismatch);
+        }
+        // We must be able to split the given data into appropriately sized chunks.
+        if !(len * size_t).is_multiple_of(size_s) {
+            return Err(ReinterpretError::MisalignedLength);
+        }
+        Ok((len * size_t) / size_s)
+    } else {
+        if size_s != 0 {
+            return Err(ReinterpretError::ZeroSizedMismatch);
+        }
+        Ok(len)
+    }
+}
+
+/// Verify that `ptr` satisfies the alignment required by `S`, otherwise the reinterpreted data is
+/// instantly undefined behaviour.
+fn check_alignment<S>(ptr: usize) -> Result<(), ReinterpretError> {
+    if !ptr.is_multiple_of(align_of::<S>()) {
+        return Err(ReinterpretError::MisalignedPointer);
+    }
+    Ok(())
+}
+
+/// Reinterpret a given slice as a slice of another type. This function checks that the resulting
+/// slice is appropriately sized.
+///
+/// # Safety
+///
+/// The caller must ensure that every bit pattern in the source is a valid value of the target type
+/// `S`; this function checks the layout but not the validity of the reinterpreted contents.
+pub unsafe fn reinterpret_mut_slice<T, S>(slice: &mut [T]) -> &mut [S] {
+    try_reinterpret_mut_slice(slice).unwrap()
+}
+
+/// Reinterpret a given slice as a slice of another type. This function checks that the resulting
+/// slice is appropriately sized.
+///
+/// # Safety
+///
+/// The caller must ensure that every bit pattern in the source is a valid value of the target type
+/// `S`; this function checks the layout but not the validity of the reinterpreted contents.
+pub unsafe fn reinterpret_slice<T, S>(slice: &[T]) -> &[S] {
+    try_reinterpret_slice(slice).unwrap()
+}
+
+/// Reinterpret a given `Vec` as a `Vec` of another type. This function checks that the resulting
+/// `Vec` is appropriately sized.
+///
+/// # Safety
+///
+/// The caller must ensure that every bit pattern in the source is a valid value of the target type
+/// `S`; this function checks the layout but not the validity of the reinterpreted contents.
+/// Additionally, the allocation is reused in place, so reinterpreting to a type with a different
+/// alignment than `T` produces a `Vec` that deallocates with a mismatched `Layout`; use
+/// [`reinterpret_vec_or_copy`] when `align_of::<S>()` may differ from `align_of::<T>()`.
+#[cfg(feature = "alloc")]
+pub unsafe fn reinterpret_vec<T, S>(vec: Vec<T>) -> Vec<S> {
+    try_reinterpret_vec(vec).unwrap()
+}
+
+/// Reinterpret a given `Vec` as a `Vec` of another type, falling back to a copy when the zero-copy
+/// conversion cannot describe the existing allocation.
+///
+/// Because `shrink_to_fit` may leave a capacity that still does not divide into the target element
+/// size, in-place reinterpretation of a runtime-sized `Vec` cannot be guaranteed. This function
+/// takes the zero-copy path whenever the capacity divides cleanly and the allocation is suitably
+/// aligned, and otherwise allocates a fresh correctly-sized `Vec<S>` and copies the bytes across,
+/// giving callers a single conversion that never panics on a capacity or alignment mismatch.
+///
+/// Like the other functions in this crate, this is unsafe because it does not verify that the
+/// source bit patterns are valid values of the target type.
+///
+/// # Safety
+///
+/// The caller must ensure that every bit pattern in the source is a valid value of the target type
+/// `S`. The bytes are reinterpreted verbatim, so the source elements' destructors are not run; this
+/// is only sound when `T` owns no resource that would be leaked by skipping its `Drop`.
+#[cfg(feature = "alloc")]
+pub unsafe fn reinterpret_vec_or_copy<T, S>(mut vec: Vec<T>) -> Vec<S> {
+    let size_t = size_of::<T>();
+    let size_s = size_of::<S>();
+    // The length divisibility and zero-sized rules are inherent to the two layouts and cannot be
+    // worked around by copying, so a violation here is a genuine misuse.
+    let nu_len = reinterpreted_len::<T, S>(vec.len())
+        .expect("Vec cannot be reinterpreted: incompatible element sizes");
+
+    let capacity_divides = size_t == 0 || (vec.capacity() * size_t).is_multiple_of(size_s);
+    // Reusing the allocation in place is only sound when the target shares the source's alignment:
+    // the eventual `dealloc` uses `S`'s `Layout`, which must match the `Layout` the buffer was
+    // allocated with for `T`. A merely suitably-aligned pointer is not sufficient, so anything that
+    // changes the alignment takes the copy path below.
+    let alignment_matches = align_of::<S>() == align_of::<T>();
+
+    if capacity_divides && alignment_matches {
+        return try_reinterpret_vec(vec).unwrap();
+    }
+
+    // The allocation cannot be reused as-is; build a fresh `Vec<S>` and move the bytes over before
+    // the original `Vec` is dropped.
+    let byte_len = vec.len() * size_t;
+    let mut nu_vec: Vec<S> = Vec::with_capacity(nu_len);
+    core::ptr::copy_nonoverlapping(
+        vec.as_ptr() as *const u8,
+        nu_vec.as_mut_ptr() as *mut u8,
+        byte_len,
+    );
+    nu_vec.set_len(nu_len);
+    // The bytes are now owned by `nu_vec`. Clear the original's length so that dropping it frees
+    // the old allocation without running `T`'s destructors on memory that has been moved out,
+    // mirroring the way the zero-copy path `forget`s the source.
+    vec.set_len(0);
     nu_vec
 }
 
-#[cfg(test)]
+/// A byte order to normalize integer and float buffers to when reinterpreting raw bytes.
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// The byte order of the host machine. Reinterpreting with this order never swaps bytes; data
+    /// is still copied when it is not suitably aligned for the target type.
+    Native,
+    /// Little-endian byte order.
+    Little,
+    /// Big-endian byte order.
+    Big,
+}
+
+#[cfg(all(feature = "alloc", target_endian = "little"))]
+const HOST_BYTE_ORDER: ByteOrder = ByteOrder::Little;
+#[cfg(all(feature = "alloc", target_endian = "big"))]
+const HOST_BYTE_ORDER: ByteOrder = ByteOrder::Big;
+
+#[cfg(feature = "alloc")]
+impl ByteOrder {
+    /// Whether interpreting data in this order requires swapping the bytes of each element on the
+    /// host machine.
+    fn needs_swap(self) -> bool {
+        match self {
+            ByteOrder::Native => false,
+            order => order != HOST_BYTE_ORDER,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod private {
+    pub trait Sealed {}
+}
+
+/// A primitive integer or float whose bytes can be swapped to convert between byte orders.
+///
+/// This trait is sealed: it is implemented only for the built-in integer and floating point types,
+/// which are exactly the types for which byte-order normalization is well defined.
+#[cfg(feature = "alloc")]
+pub trait EndianPrimitive: private::Sealed + Copy {
+    /// Return this value with its bytes reversed.
+    fn swap_bytes(self) -> Self;
+    /// Decode a value from its native-endian byte representation. `bytes` must be exactly
+    /// `size_of::<Self>()` bytes long. This imposes no alignment requirement on the input.
+    fn from_ne_bytes(bytes: &[u8]) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_endian_primitive_int {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl private::Sealed for $ty {}
+            impl EndianPrimitive for $ty {
+                fn swap_bytes(self) -> Self {
+                    <$ty>::swap_bytes(self)
+                }
+                fn from_ne_bytes(bytes: &[u8]) -> Self {
+                    <$ty>::from_ne_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_endian_primitive_float {
+    ($($ty:ty => $bits:ty),* $(,)?) => {
+        $(
+            impl private::Sealed for $ty {}
+            impl EndianPrimitive for $ty {
+                fn swap_bytes(self) -> Self {
+                    <$ty>::from_bits(self.to_bits().swap_bytes())
+                }
+                fn from_ne_bytes(bytes: &[u8]) -> Self {
+                    <$ty>::from_ne_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_endian_primitive_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+#[cfg(feature = "alloc")]
+impl_endian_primitive_float!(f32 => u32, f64 => u64);
+
+/// Reinterpret a byte slice as a slice of a primitive type, normalizing from the given byte order.
+///
+/// When `order` matches the host byte order and the input is suitably aligned, the borrowed
+/// zero-copy view is returned unchanged; otherwise a fresh buffer is allocated and each element is
+/// decoded from its raw bytes, swapping byte order when required. Decoding through the bytes
+/// imposes no alignment requirement, so unaligned sub-slices of packed binary data are handled
+/// without panicking while matching-endian, aligned input keeps the fast path.
+#[cfg(feature = "alloc")]
+pub fn reinterpret_slice_with_endianness<S: EndianPrimitive>(
+    bytes: &[u8],
+    order: ByteOrder,
+) -> Cow<'_, [S]> {
+    // Fast path: a matching byte order lets us hand back a zero-copy view, but only when the input
+    // also satisfies `S`'s alignment.
+    if !order.needs_swap() {
+        if let Ok(view) = unsafe { try_reinterpret_slice::<u8, S>(bytes) } {
+            return Cow::Borrowed(view);
+        }
+    }
+    // General path: decode each element from its bytes, which has no alignment requirement.
+    let size_s = size_of::<S>();
+    assert!(
+        bytes.len().is_multiple_of(size_s),
+        "byte slice length is not a multiple of the target element size"
+    );
+    let decoded = bytes
+        .chunks_exact(size_s)
+        .map(|chunk| {
+            let value = S::from_ne_bytes(chunk);
+            if order.needs_swap() {
+                value.swap_bytes()
+            } else {
+                value
+            }
+        })
+        .collect();
+    Cow::Owned(decoded)
+}
+
+/// Reinterpret a byte `Vec` as a `Vec` of a primitive type, normalizing from the given byte order.
+///
+/// Elements are byte-swapped in place when `order` does not match the host. The conversion uses
+/// [`reinterpret_vec_or_copy`] so it never panics on a capacity or alignment mismatch.
+#[cfg(feature = "alloc")]
+pub fn reinterpret_vec_with_endianness<S: EndianPrimitive>(
+    bytes: Vec<u8>,
+    order: ByteOrder,
+) -> Vec<S> {
+    let mut vec: Vec<S> = unsafe { reinterpret_vec_or_copy(bytes) };
+    if order.needs_swap() {
+        for x in vec.iter_mut() {
+            *x = x.swap_bytes();
+        }
+    }
+    vec
+}
+
+/// A marker for types that can be safely reinterpreted from and to any other
+/// `TriviallyReinterpretable` type with the same memory layout.
+///
+/// Implementing this trait is a promise that the type has no padding bytes, that every bit pattern
+/// is a valid value, and that it contains no interior pointers or other data whose meaning depends
+/// on its address. Given two such types of matching size, reinterpreting between them is sound,
+/// which lets the `safe_reinterpret_*` wrappers below drop the `unsafe` requirement.
+///
+/// This is the same discipline that crates like `zerocopy` encode with their `FromBytes`/`AsBytes`
+/// marker traits.
+///
+/// # Safety
+///
+/// Implementors must have no padding bytes, must treat every bit pattern as a valid value, and must
+/// contain no interior pointers or other address-dependent data. Implementing this trait for a type
+/// that violates any of these lets safe code produce invalid values and is undefined behaviour.
+pub unsafe trait TriviallyReinterpretable {}
+
+macro_rules! impl_trivially_reinterpretable {
+    ($($ty:ty),* $(,)?) => {
+        $( unsafe impl TriviallyReinterpretable for $ty {} )*
+    };
+}
+
+impl_trivially_reinterpretable!(u8, u16, u32, u64, u128, usize);
+impl_trivially_reinterpretable!(i8, i16, i32, i64, i128, isize);
+impl_trivially_reinterpretable!(f32, f64);
+
+// An array of trivially reinterpretable elements is itself trivially reinterpretable: arrays add
+// no padding around their elements.
+unsafe impl<U: TriviallyReinterpretable, const N: usize> TriviallyReinterpretable for [U; N] {}
+
+/// Reinterpret a slice of one `TriviallyReinterpretable` type as a slice of another. This is a safe
+/// wrapper around [`reinterpret_slice`] for types whose layout makes the conversion sound.
+pub fn safe_reinterpret_slice<T, S>(slice: &[T]) -> &[S]
+where
+    T: TriviallyReinterpretable,
+    S: TriviallyReinterpretable,
+{
+    unsafe { reinterpret_slice(slice) }
+}
+
+/// Reinterpret a mutable slice of one `TriviallyReinterpretable` type as a mutable slice of
+/// another. This is a safe wrapper around [`reinterpret_mut_slice`].
+pub fn safe_reinterpret_mut_slice<T, S>(slice: &mut [T]) -> &mut [S]
+where
+    T: TriviallyReinterpretable,
+    S: TriviallyReinterpretable,
+{
+    unsafe { reinterpret_mut_slice(slice) }
+}
+
+/// Reinterpret a `Vec` of one `TriviallyReinterpretable` type as a `Vec` of another. This is a safe
+/// wrapper around [`reinterpret_vec_or_copy`].
+///
+/// The `_or_copy` variant is used rather than [`reinterpret_vec`] because two
+/// `TriviallyReinterpretable` types may have different alignments; reusing the allocation in place
+/// across an alignment change would deallocate with a mismatched `Layout`, so the conversion falls
+/// back to a copy in that case to remain sound from safe code.
+#[cfg(feature = "alloc")]
+pub fn safe_reinterpret_vec<T, S>(vec: Vec<T>) -> Vec<S>
+where
+    T: TriviallyReinterpretable,
+    S: TriviallyReinterpretable,
+{
+    unsafe { reinterpret_vec_or_copy(vec) }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
 
     /// Check that we can reinterpret a slice of `[f64;3]`s as a slice of `f64`s.
     #[test]
@@ -242,6 +631,118 @@ mod tests {
         assert_eq!(nu_vec, exp_vec);
     }
 
+    /// Check that reinterpreting a misaligned slice is caught before producing an invalid pointer.
+    #[test]
+    #[should_panic]
+    fn misaligned_slice_test() {
+        // Start from a `u32`-aligned buffer, then offset by a single byte to guarantee a pointer
+        // that no longer satisfies `u32`'s alignment while keeping the size divisible.
+        let aligned: Vec<u32> = vec![0; 4];
+        let bytes: &[u8] = unsafe { reinterpret_slice(aligned.as_slice()) };
+        let _: &[u32] = unsafe { reinterpret_slice(&bytes[1..13]) };
+    }
+
+    /// Check that the safe wrappers reinterpret `TriviallyReinterpretable` types without `unsafe`.
+    #[test]
+    fn safe_reinterpret_test() {
+        let flat: Vec<f64> = vec![0.1, 1.0, 2.0, 1.2, 1.4, 2.1];
+        let points: &[[f64; 3]] = safe_reinterpret_slice(flat.as_slice());
+        assert_eq!(points, &[[0.1, 1.0, 2.0], [1.2, 1.4, 2.1]]);
+
+        let nu_vec: Vec<[f64; 3]> = safe_reinterpret_vec(flat.clone());
+        assert_eq!(nu_vec, vec![[0.1, 1.0, 2.0], [1.2, 1.4, 2.1]]);
+
+        let mut buf: Vec<u16> = vec![1, 2, 3, 4];
+        let bytes: &mut [u8] = safe_reinterpret_mut_slice(buf.as_mut_slice());
+        assert_eq!(bytes.len(), 8);
+
+        // Reinterpreting to a more strictly aligned type from safe code must not deallocate with a
+        // mismatched `Layout`; the copying fallback keeps this sound.
+        let raw: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0];
+        let ints: Vec<u32> = safe_reinterpret_vec(raw);
+        assert_eq!(ints, vec![0u32, 0]);
+    }
+
+    /// Check that the fallible variants report errors instead of panicking.
+    #[test]
+    fn try_reinterpret_test() {
+        // A three byte slice cannot be split into `u32`s.
+        let bytes: &[u8] = &[1, 2, 3];
+        let res: Result<&[u32], _> = unsafe { try_reinterpret_slice(bytes) };
+        assert_eq!(res, Err(ReinterpretError::MisalignedLength));
+
+        // A matching conversion succeeds and agrees with the panicking function.
+        let flat: Vec<f64> = vec![0.1, 1.0, 2.0, 1.2, 1.4, 2.1];
+        let res: Result<&[[f64; 3]], _> = unsafe { try_reinterpret_slice(flat.as_slice()) };
+        assert_eq!(res.unwrap(), &[[0.1, 1.0, 2.0], [1.2, 1.4, 2.1]]);
+    }
+
+    /// Check that the copying fallback succeeds even when the capacity cannot be reused in place.
+    #[test]
+    fn reinterpret_vec_or_copy_test() {
+        // Build a `Vec<u8>` whose capacity is not a multiple of 3 so the zero-copy path into
+        // `[u8; 3]` cannot describe the allocation.
+        let mut bytes: Vec<u8> = Vec::with_capacity(8);
+        bytes.extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+
+        let triples: Vec<[u8; 3]> = unsafe { reinterpret_vec_or_copy(bytes) };
+        assert_eq!(triples, vec![[0, 1, 2], [3, 4, 5]]);
+
+        // The zero-copy path is still taken when the allocation is compatible.
+        let flat: Vec<f64> = vec![0.1, 1.0, 2.0, 1.2, 1.4, 2.1];
+        let points: Vec<[f64; 3]> = unsafe { reinterpret_vec_or_copy(flat) };
+        assert_eq!(points, vec![[0.1, 1.0, 2.0], [1.2, 1.4, 2.1]]);
+
+        // Casting to a more strictly aligned type must copy, even when the byte pointer happens to
+        // be aligned, so the resulting `Vec` deallocates with a `Layout` matching its allocation.
+        let words: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0];
+        let ints: Vec<u32> = unsafe { reinterpret_vec_or_copy(words) };
+        assert_eq!(ints, vec![0u32, 0]);
+    }
+
+    /// Check endianness normalization on both the zero-copy and byte-swapping paths.
+    #[test]
+    fn endianness_test() {
+        // Start from `u16`-aligned storage so the zero-copy view is well aligned.
+        let words: Vec<u16> = vec![0x0102, 0x0304];
+        let bytes: &[u8] = unsafe { reinterpret_slice(words.as_slice()) };
+
+        let native: Cow<[u16]> = reinterpret_slice_with_endianness(bytes, ByteOrder::Native);
+        assert!(matches!(native, Cow::Borrowed(_)));
+        assert_eq!(&*native, &[0x0102u16, 0x0304]);
+
+        let big: Cow<[u16]> = reinterpret_slice_with_endianness(bytes, ByteOrder::Big);
+        if ByteOrder::Big.needs_swap() {
+            assert_eq!(&*big, &[0x0201u16, 0x0403]);
+        } else {
+            assert_eq!(&*big, &[0x0102u16, 0x0304]);
+        }
+
+        // Unaligned packed data, such as a sub-slice of a byte buffer, must be decoded rather than
+        // panicking. Offset a `u32`-aligned buffer by one byte to force misalignment.
+        let aligned: Vec<u32> = vec![0, 0];
+        let raw: &[u8] = unsafe { reinterpret_slice(aligned.as_slice()) };
+        let unaligned = &raw[1..5];
+        let decoded: Cow<[u32]> = reinterpret_slice_with_endianness(unaligned, ByteOrder::Big);
+        assert!(matches!(decoded, Cow::Owned(_)));
+        assert_eq!(decoded.len(), 1);
+
+        // The `Vec` helper casts `u8` to a more strictly aligned `u32`; it must stay sound (copying
+        // when alignments differ) and still normalize the byte order. Big-endian `00 00 00 01`
+        // decodes to 1 regardless of host endianness.
+        let packed: Vec<u8> = vec![0, 0, 0, 1];
+        let values: Vec<u32> = reinterpret_vec_with_endianness(packed, ByteOrder::Big);
+        assert_eq!(values, vec![1u32]);
+    }
+
+    /// Check that reinterpreting an empty slice does not trip the alignment check.
+    #[test]
+    fn empty_slice_test() {
+        let empty: &[u8] = &[];
+        let nu: &[u32] = unsafe { reinterpret_slice(empty) };
+        assert!(nu.is_empty());
+    }
+
     /// Test reinterpreting collections of zero size structs.
     #[test]
     fn zero_size_test() {